@@ -1,6 +1,6 @@
 use bevy_ecs::{
     entity::Entity,
-    prelude::{Component, World},
+    prelude::{Component, Event, Events, World},
 };
 
 use crate::{
@@ -20,10 +20,59 @@ pub trait WithId {
     fn id(&self) -> Entity;
 }
 
+/// An event emitted when an [`Animation`] crosses one of the progress thresholds registered
+/// via [`Animation::with_event`]. Consumed like any other `bevy_ecs` event, e.g. to chain a
+/// follow-up animation or play a sound once a move finishes.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationEvent {
+    pub entity: Entity,
+    pub label: String,
+}
+
+impl AnimationEvent {
+    pub fn new(entity: Entity, label: impl Into<String>) -> Self {
+        Self {
+            entity,
+            label: label.into(),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Animations<C: Interpolate + Component>(pub Vec<Animation<C>>);
 
-#[derive(Component, Debug, Clone, Copy)]
+/// How many times an [`Animation`] replays its `begin`-to-`end` span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    #[default]
+    Once,
+    Count(u32),
+    Forever,
+}
+
+/// How an [`Animation`]'s contribution combines with other animations simultaneously active on
+/// the same property, evaluated by [`Animations::blend`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BlendMode {
+    /// Participates in the weighted average across all other `Override` animations.
+    #[default]
+    Override,
+    /// Layered on top of the `Override` result afterwards, e.g. a `by`-relative shake riding on
+    /// top of a `to` move.
+    Additive,
+}
+
+/// One point in an [`Animation::keyframes`] sequence: `offset` is its normalized position in
+/// `[0, 1]`, `value` the property at that point, and `ease` the rate function used to animate
+/// into it from the previous keyframe.
+#[derive(Debug, Clone, Copy)]
+pub struct Keyframe<T> {
+    pub offset: f32,
+    pub value: T,
+    pub ease: EaseType,
+}
+
+#[derive(Component, Debug, Clone)]
 pub struct Animation<T> {
     pub(crate) begin: Option<T>,
     pub(crate) end: Value<T>,
@@ -33,6 +82,33 @@ pub struct Animation<T> {
     pub(crate) init_duration: bool,
     pub(crate) init_start_time: bool,
     pub(crate) init_rate_func: bool,
+    pub(crate) events: Vec<(f32, AnimationEvent)>,
+    /// Progress evaluated on the previous frame, used to edge-trigger `events`. `-1.0` is the
+    /// sentinel for "not yet evaluated" so a threshold at `0.0` still fires on the first frame.
+    pub(crate) last_progress: f32,
+    pub(crate) repeat: Repeat,
+    pub(crate) ping_pong: bool,
+    /// Fraction, in `[0, 1]`, of the tail of each loop cycle spent blending the property back
+    /// toward `begin` instead of snapping straight from `end` to the next cycle's `begin`.
+    pub(crate) blend_back: f32,
+    pub(crate) weight: f32,
+    pub(crate) blend_mode: BlendMode,
+    /// Seconds after `start_time` over which `weight` ramps up from `0.0`, e.g. the incoming
+    /// side of a crossfade. `0.0` disables ramping.
+    pub(crate) fade_in: f32,
+    /// Seconds before the animation ends over which `weight` ramps back down to `0.0`, e.g. the
+    /// outgoing side of a crossfade. `0.0` disables ramping.
+    pub(crate) fade_out: f32,
+    /// When set via [`Animation::keyframes`], `update` interpolates across these points instead
+    /// of the single `begin`/`end` span, each with its own rate function. `set_properties` only
+    /// ever touches the top-level `rate_func`, so per-keyframe eases always pass through intact.
+    pub(crate) keyframes: Option<Vec<Keyframe<T>>>,
+    /// The first keyframe's still-unresolved source, when [`Animation::keyframes`] was built
+    /// from a `Value::Relative`/`Value::From` starting point. `update` resolves it into
+    /// `keyframes[0].value` the first time `begin` is captured, the same way `Value::Relative`
+    /// resolves against `begin` for a plain `by` animation; `has_target`/`init_from_target`
+    /// resolve a `Value::From` starting point the same way they do for `end`.
+    pub(crate) first_value: Option<Value<T>>,
 }
 
 impl<T> Animation<T>
@@ -49,6 +125,17 @@ where
             init_duration: true,
             init_start_time: true,
             init_rate_func: true,
+            events: Vec::new(),
+            last_progress: -1.0,
+            repeat: Repeat::Once,
+            ping_pong: false,
+            blend_back: 0.0,
+            weight: 1.0,
+            blend_mode: BlendMode::Override,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            keyframes: None,
+            first_value: None,
         }
     }
 
@@ -62,6 +149,17 @@ where
             init_duration: true,
             init_start_time: true,
             init_rate_func: true,
+            events: Vec::new(),
+            last_progress: -1.0,
+            repeat: Repeat::Once,
+            ping_pong: false,
+            blend_back: 0.0,
+            weight: 1.0,
+            blend_mode: BlendMode::Override,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            keyframes: None,
+            first_value: None,
         }
     }
 
@@ -75,7 +173,105 @@ where
             init_duration: true,
             init_start_time: true,
             init_rate_func: true,
+            events: Vec::new(),
+            last_progress: -1.0,
+            repeat: Repeat::Once,
+            ping_pong: false,
+            blend_back: 0.0,
+            weight: 1.0,
+            blend_mode: BlendMode::Override,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            keyframes: None,
+            first_value: None,
+        }
+    }
+
+    /// Builds an animation over an ordered list of `(offset, value, ease)` points instead of a
+    /// single `begin`-to-`end` span. `first`'s value may be `Value::Relative`/`Value::From`
+    /// instead of a fixed value, resolved against the live property the same way `by`/
+    /// `to_target` resolve `end`; `first`'s offset must be the lowest in the sequence (normally
+    /// `0.0`). `update` locates the segment straddling the current progress, remaps it to
+    /// `[0, 1]` within that segment, and applies the arriving point's own `ease`. The last point
+    /// fixes `end`, so `has_target`/`init_from_target` keep working the same way they do for
+    /// `to`/`by`/`to_target`.
+    pub fn keyframes(first: (f32, Value<T>, EaseType), rest: Vec<(f32, T, EaseType)>) -> Self {
+        let (first_offset, first_value, first_ease) = first;
+        let placeholder = match &first_value {
+            Value::Absolute(value) => *value,
+            _ => rest.first().map(|(_, value, _)| *value).expect(
+                "Animation::keyframes needs at least one concrete waypoint when the first \
+                 keyframe is Value::Relative/Value::From",
+            ),
+        };
+        let begin = match &first_value {
+            Value::Absolute(value) => Some(*value),
+            Value::Relative(_) | Value::From(_) => None,
+        };
+
+        let mut points = vec![(first_offset, placeholder, first_ease)];
+        points.extend(rest);
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let end = points
+            .last()
+            .expect("Animation::keyframes requires at least one keyframe")
+            .1;
+
+        let mut animation = Self::to(end);
+        animation.begin = begin;
+        animation.first_value = match first_value {
+            Value::Absolute(_) => None,
+            relative_or_target => Some(relative_or_target),
+        };
+        animation.keyframes = Some(
+            points
+                .into_iter()
+                .map(|(offset, value, ease)| Keyframe {
+                    offset: offset.clamp(0.0, 1.0),
+                    value,
+                    ease,
+                })
+                .collect(),
+        );
+        animation
+    }
+
+    /// Registers `event` to fire once progress crosses `progress` (clamped to `[0, 1]`).
+    /// Dispatch is edge-triggered by [`Animation::dispatch_events`], which `update`/
+    /// `update_position` call on every invocation: a threshold fires exactly once per crossing,
+    /// in either direction, so a non-monotonic `rate_func` can't double-fire it, and a threshold
+    /// of `1.0` still fires when the animation clamps at completion.
+    pub fn with_event(mut self, progress: f32, event: AnimationEvent) -> Self {
+        self.events.push((progress.clamp(0.0, 1.0), event));
+        self
+    }
+
+    /// Dispatches any `events` whose threshold lies between the last evaluated progress and
+    /// `progress` into the `Events<AnimationEvent>` resource. Called by `update`/
+    /// `update_position` on every invocation so registering an event is enough on its own.
+    fn dispatch_events(&mut self, progress: f32, world: &mut World) {
+        if self.events.is_empty() {
+            self.last_progress = progress;
+            return;
+        }
+
+        let previous = self.last_progress;
+        let (lo, hi) = if progress >= previous {
+            (previous, progress)
+        } else {
+            (progress, previous)
+        };
+
+        let mut events = world.resource_mut::<Events<AnimationEvent>>();
+        for (threshold, event) in &self.events {
+            let crossed = *threshold > lo && *threshold <= hi;
+            let fires_at_completion = *threshold >= 1.0 && progress >= 1.0 && previous < 1.0;
+            if crossed || fires_at_completion {
+                events.send(event.clone());
+            }
         }
+
+        self.last_progress = progress;
     }
 
     pub fn with_duration(mut self, duration: f32) -> Self {
@@ -96,23 +292,198 @@ where
         self
     }
 
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn with_ping_pong(mut self) -> Self {
+        self.ping_pong = true;
+        self
+    }
+
+    pub fn with_blend_back(mut self, blend_back: f32) -> Self {
+        self.blend_back = blend_back.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_additive(mut self) -> Self {
+        self.blend_mode = BlendMode::Additive;
+        self
+    }
+
+    /// Ramps `weight` up from `0.0` over `seconds` after `start_time`, e.g. so the incoming side
+    /// of a crossfade eases in instead of jumping straight to full weight.
+    pub fn with_fade_in(mut self, seconds: f32) -> Self {
+        self.fade_in = seconds.max(0.0);
+        self
+    }
+
+    /// Ramps `weight` down to `0.0` over `seconds` before the animation ends, e.g. so the
+    /// outgoing side of a crossfade eases out instead of cutting off at full weight.
+    pub fn with_fade_out(mut self, seconds: f32) -> Self {
+        self.fade_out = seconds.max(0.0);
+        self
+    }
+
+    /// Multiplier in `[0, 1]` applied to `weight` in [`Animations::blend`]/`blend_position`:
+    /// ramps up over `fade_in` seconds after `start_time` and back down over `fade_out` seconds
+    /// before `duration` ends, so a crossfading pair ramps 100% outgoing -> 100% incoming across
+    /// their overlap instead of averaging flat for its whole span.
+    fn fade_factor(&self, elapsed: f32) -> f32 {
+        let mut factor = 1.0_f32;
+        if self.fade_in > 0.0 {
+            factor = factor.min((elapsed / self.fade_in).clamp(0.0, 1.0));
+        }
+        if self.fade_out > 0.0 {
+            let remaining = self.duration - elapsed;
+            factor = factor.min((remaining / self.fade_out).clamp(0.0, 1.0));
+        }
+        factor
+    }
+
+    /// Resolves this animation's value at `progress` against `current` without mutating the
+    /// shared property, lazily capturing `begin` from `current` the same way `update` does.
+    /// Used by [`Animations::blend`] so several concurrently active animations can each sample
+    /// their own value before being combined. Delegates to `sample_keyframes` when this
+    /// animation was built via `Animation::keyframes`, the same way `update` does, so a
+    /// keyframed animation blends across its intermediate points instead of a flat
+    /// `begin` -> last-keyframe line. Resolves `Value::Relative` against `begin` the same way
+    /// `update_position` does; still returns `None` for an unresolved `Value::From` target, which needs a
+    /// target entity's property rather than just `current`.
+    pub fn resolve_value(&mut self, current: T, progress: f32) -> Option<T>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        if self.begin.is_none() {
+            self.begin = Some(current);
+            self.resolve_first_keyframe(current);
+        }
+
+        if let Some(keyframes) = &self.keyframes {
+            return Self::sample_keyframes(keyframes, progress);
+        }
+
+        match (&self.begin, &self.end) {
+            (Some(begin), Value::Absolute(to)) => Some(begin.interp(to, progress)),
+            (Some(begin), Value::Relative(by)) => Some(begin.interp(&(*begin + *by), progress)),
+            _ => None,
+        }
+    }
+
+    /// As [`Animation::resolve_value`], but returns the delta from `begin` rather than the
+    /// absolute sample. Used by [`Animations::blend`] to layer `Additive` animations on top of
+    /// the `Override` result instead of replacing it.
+    pub fn resolve_delta(&mut self, current: T, progress: f32) -> Option<T>
+    where
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+    {
+        let value = self.resolve_value(current, progress)?;
+        self.begin.map(|begin| value - begin)
+    }
+
+    /// Whether the animation is still active `elapsed` seconds after `start_time`, and if so,
+    /// the local `[0, 1]` progress the scheduler should feed into `update`/`update_position`
+    /// instead of the raw `elapsed / duration` ratio. Replaces `start_time + duration` as the
+    /// definitive end for `Repeat::Forever`, which never returns `None` once `elapsed` reaches
+    /// `0.0`.
+    pub fn active_progress(&self, elapsed: f32) -> Option<f32> {
+        if elapsed < 0.0 {
+            return None;
+        }
+
+        let total_cycles = match self.repeat {
+            Repeat::Once => 1.0,
+            Repeat::Count(count) => count.max(1) as f32,
+            Repeat::Forever => f32::INFINITY,
+        };
+
+        if self.duration <= 0.0 {
+            return Some(1.0);
+        }
+
+        if total_cycles.is_finite() && elapsed > total_cycles * self.duration {
+            // Past the terminal frame (which `local_progress` below already maps onto the
+            // final cycle's last value): fully inactive.
+            return None;
+        }
+
+        Some(self.local_progress(elapsed))
+    }
+
+    /// Maps raw elapsed time onto local `[0, 1]` progress within the current loop cycle,
+    /// reversing on odd cycles when `ping_pong` is set and, within the final `blend_back`
+    /// fraction of each cycle, easing back toward `begin` so seamless loops don't snap.
+    fn local_progress(&self, elapsed: f32) -> f32 {
+        let cycle_progress = elapsed / self.duration;
+        let mut cycle_index = cycle_progress.floor();
+        let mut local = cycle_progress - cycle_index;
+        if local <= 0.0 && cycle_progress > 0.0 {
+            // Landed exactly on a cycle seam: this is the tail of the cycle that just
+            // completed, not the head of the next one, so correct `cycle_index` along with
+            // `local` before deciding ping-pong parity below. Otherwise the not-yet-started
+            // next cycle's parity picks the reversal direction instead of the one that was
+            // actually animating up to this instant.
+            cycle_index -= 1.0;
+            local = 1.0;
+        }
+
+        if self.ping_pong && (cycle_index as i64).rem_euclid(2) == 1 {
+            local = 1.0 - local;
+        }
+
+        if self.blend_back > 0.0 && local > 1.0 - self.blend_back {
+            let t = (local - (1.0 - self.blend_back)) / self.blend_back;
+            local *= 1.0 - t;
+        }
+
+        local.clamp(0.0, 1.0)
+    }
+
     pub fn has_target(&self) -> Option<Entity> {
         match self.end {
             Value::From(entity) => Some(entity),
-            _ => None,
+            _ => match &self.first_value {
+                Some(Value::From(entity)) => Some(*entity),
+                _ => None,
+            },
         }
     }
 
     pub fn init_from_target(&mut self, end: &T) {
-        match &self.end {
-            Value::From(entity) => {
-                self.end = Value::Absolute(*end);
-            }
-            _ => (),
+        if let Value::From(_) = &self.end {
+            self.end = Value::Absolute(*end);
+        }
+        if let Some(Value::From(_)) = &self.first_value {
+            self.first_value = Some(Value::Absolute(*end));
         }
     }
 
-    pub fn update(&mut self, property: &mut T, progress: f32) {
+    pub fn update(&mut self, property: &mut T, progress: f32, world: &mut World)
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        if self.keyframes.is_some() {
+            if self.begin.is_none() {
+                self.begin = Some(*property);
+                self.resolve_first_keyframe(*property);
+            }
+            if let Some(value) = self
+                .keyframes
+                .as_ref()
+                .and_then(|keyframes| Self::sample_keyframes(keyframes, progress))
+            {
+                *property = value;
+            }
+            self.dispatch_events(progress, world);
+            return;
+        }
+
         match (&mut self.begin, &mut self.end) {
             (Some(begin), Value::Absolute(to)) => *property = begin.interp(&to, progress),
             (None, Value::Absolute(to)) => {
@@ -120,11 +491,54 @@ where
             }
             _ => (),
         }
+        self.dispatch_events(progress, world);
+    }
+
+    /// Resolves `self.first_value` (when set) into `keyframes[0].value` the first time `begin`
+    /// is captured from `current`, the same way a plain `by`/`to_target` animation resolves
+    /// `Value::Relative`/`Value::From` against the live property. Shared by `update` and
+    /// `resolve_value` so both paths keep keyframed animations in sync.
+    fn resolve_first_keyframe(&mut self, current: T)
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        let Some(keyframes) = &mut self.keyframes else {
+            return;
+        };
+        if let (Some(first_value), Some(first_keyframe)) =
+            (&self.first_value, keyframes.first_mut())
+        {
+            first_keyframe.value = match first_value {
+                Value::Absolute(value) => *value,
+                Value::Relative(by) => current + *by,
+                // Cross-entity target: resolved by `init_from_target` before `update`/
+                // `resolve_value` runs, the same way it is for `end`; until then fall back to
+                // `current` so sampling doesn't read a stale placeholder.
+                Value::From(_) => current,
+            };
+        }
+    }
+
+    fn sample_keyframes(keyframes: &[Keyframe<T>], progress: f32) -> Option<T> {
+        if keyframes.len() < 2 {
+            return keyframes.first().map(|keyframe| keyframe.value);
+        }
+
+        let progress = progress.clamp(0.0, 1.0);
+        let segment = keyframes
+            .windows(2)
+            .find(|pair| progress <= pair[1].offset)
+            .unwrap_or(&keyframes[keyframes.len() - 2..]);
+        let (from, to) = (&segment[0], &segment[1]);
+
+        let span = (to.offset - from.offset).max(f32::EPSILON);
+        let local = ((progress - from.offset) / span).clamp(0.0, 1.0);
+        Some(from.value.interp(&to.value, to.ease.apply(local)))
     }
 }
 
 impl Animation<Position> {
-    pub fn update_position(&mut self, property: &mut Position, progress: f32) {
+    pub fn update_position(&mut self, property: &mut Position, progress: f32, world: &mut World) {
         match (&mut self.begin, &mut self.end) {
             (Some(begin), Value::Absolute(to)) => *property = begin.interp(&to, progress),
             (Some(begin), Value::Relative(by)) => {
@@ -135,6 +549,140 @@ impl Animation<Position> {
             }
             _ => (),
         }
+        self.dispatch_events(progress, world);
+    }
+
+    /// As [`Animation::resolve_value`], for `Position`: samples keyframes the same way and
+    /// resolves `Value::Relative` against `begin` (mirroring `update_position`'s `Add`
+    /// resolution for `Position`).
+    pub fn resolve_position(&mut self, current: Position, progress: f32) -> Option<Position> {
+        if self.begin.is_none() {
+            self.begin = Some(current);
+            self.resolve_first_keyframe(current);
+        }
+
+        if let Some(keyframes) = &self.keyframes {
+            return Self::sample_keyframes(keyframes, progress);
+        }
+
+        match &self.begin {
+            Some(begin) => match &self.end {
+                Value::Absolute(to) => Some(begin.interp(to, progress)),
+                Value::Relative(by) => Some(begin.interp(&(*begin + *by), progress)),
+                Value::From(_) => None,
+            },
+            None => None,
+        }
+    }
+
+    /// As [`Animation::resolve_position`], but returns the delta from `begin` rather than the
+    /// absolute sample, for [`Animations::blend_position`]'s `Additive` pass.
+    pub fn resolve_delta_position(&mut self, current: Position, progress: f32) -> Option<Position> {
+        let value = self.resolve_position(current, progress)?;
+        self.begin.map(|begin| value - begin)
+    }
+}
+
+impl<C> Animations<C>
+where
+    C: Interpolate + Component + Copy,
+{
+    /// Combines every animation of `self` that's currently active at `elapsed` seconds past
+    /// its `start_time` into a single value for `property`, per Bevy `AnimationGraph`-style
+    /// bottom-up evaluation: `Override` animations fold into a running weighted average via
+    /// `Interpolate::interp`, then `Additive` animations (e.g. a `by`-relative shake) layer their
+    /// weighted delta from `begin` on top of that result, rather than replacing it.
+    pub fn blend(&mut self, property: &C, scene_time: f32) -> C
+    where
+        C: std::ops::Add<Output = C> + std::ops::Sub<Output = C>,
+    {
+        let mut overridden: Option<C> = None;
+        let mut running_weight = 0.0_f32;
+        let mut additive = Vec::new();
+
+        for animation in self.0.iter_mut() {
+            let elapsed = scene_time - animation.start_time;
+            let Some(progress) = animation.active_progress(elapsed) else {
+                continue;
+            };
+            let weight = animation.weight * animation.fade_factor(elapsed);
+            if weight <= 0.0 {
+                continue;
+            }
+
+            match animation.blend_mode {
+                BlendMode::Override => {
+                    let Some(value) = animation.resolve_value(*property, progress) else {
+                        continue;
+                    };
+                    running_weight += weight;
+                    overridden = Some(match overridden {
+                        Some(acc) => acc.interp(&value, weight / running_weight),
+                        None => value,
+                    });
+                }
+                BlendMode::Additive => {
+                    let Some(delta) = animation.resolve_delta(*property, progress) else {
+                        continue;
+                    };
+                    additive.push((delta, weight));
+                }
+            }
+        }
+
+        let mut result = overridden.unwrap_or(*property);
+        for (delta, weight) in additive {
+            result = result.interp(&(result + delta), weight.clamp(0.0, 1.0));
+        }
+        result
+    }
+}
+
+impl Animations<Position> {
+    /// As [`Animations::blend`], resolving `Value::Relative` targets via
+    /// [`Animation::resolve_position`] so a `by`-relative animation can blend alongside a
+    /// `to`/`to_target` one on the same entity. `Additive` animations layer their weighted delta
+    /// from `begin` on top of the `Override` result, rather than replacing it.
+    pub fn blend_position(&mut self, property: &Position, scene_time: f32) -> Position {
+        let mut overridden: Option<Position> = None;
+        let mut running_weight = 0.0_f32;
+        let mut additive = Vec::new();
+
+        for animation in self.0.iter_mut() {
+            let elapsed = scene_time - animation.start_time;
+            let Some(progress) = animation.active_progress(elapsed) else {
+                continue;
+            };
+            let weight = animation.weight * animation.fade_factor(elapsed);
+            if weight <= 0.0 {
+                continue;
+            }
+
+            match animation.blend_mode {
+                BlendMode::Override => {
+                    let Some(value) = animation.resolve_position(*property, progress) else {
+                        continue;
+                    };
+                    running_weight += weight;
+                    overridden = Some(match overridden {
+                        Some(acc) => acc.interp(&value, weight / running_weight),
+                        None => value,
+                    });
+                }
+                BlendMode::Additive => {
+                    let Some(delta) = animation.resolve_delta_position(*property, progress) else {
+                        continue;
+                    };
+                    additive.push((delta, weight));
+                }
+            }
+        }
+
+        let mut result = overridden.unwrap_or(*property);
+        for (delta, weight) in additive {
+            result = result.interp(&(result + delta), weight.clamp(0.0, 1.0));
+        }
+        result
     }
 }
 
@@ -147,7 +695,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum AnimationType {
     StrokeColor(Animation<StrokeColor>),
     FillColor(Animation<FillColor>),
@@ -229,6 +777,20 @@ fn set_properties<T: Component + Interpolate>(
     }
 }
 
+fn set_start_time<T: Component + Interpolate>(animation: &mut Animation<T>, start_time: f32) {
+    if animation.init_start_time {
+        animation.start_time = start_time;
+    }
+}
+
+fn set_fade_in<T: Component + Interpolate>(animation: &mut Animation<T>, fade_in: f32) {
+    animation.fade_in = fade_in;
+}
+
+fn set_fade_out<T: Component + Interpolate>(animation: &mut Animation<T>, fade_out: f32) {
+    animation.fade_out = fade_out;
+}
+
 #[derive(Debug, Clone)]
 pub struct EntityAnimations {
     pub(crate) entity: Entity,
@@ -274,6 +836,44 @@ impl EntityAnimations {
             AnimationType::PathCompletion(animation) => animation.start_time,
         }
     }
+    pub fn duration(&self) -> f32 {
+        match self.animations.get(0).unwrap() {
+            AnimationType::StrokeColor(animation) => animation.duration,
+            AnimationType::FillColor(animation) => animation.duration,
+            AnimationType::Position(animation) => animation.duration,
+            AnimationType::Angle(animation) => animation.duration,
+            AnimationType::Size(animation) => animation.duration,
+            AnimationType::Opacity(animation) => animation.duration,
+            AnimationType::PathCompletion(animation) => animation.duration,
+        }
+    }
+    pub fn rate_func(&self) -> EaseType {
+        match self.animations.get(0).unwrap() {
+            AnimationType::StrokeColor(animation) => animation.rate_func,
+            AnimationType::FillColor(animation) => animation.rate_func,
+            AnimationType::Position(animation) => animation.rate_func,
+            AnimationType::Angle(animation) => animation.rate_func,
+            AnimationType::Size(animation) => animation.rate_func,
+            AnimationType::Opacity(animation) => animation.rate_func,
+            AnimationType::PathCompletion(animation) => animation.rate_func,
+        }
+    }
+    pub fn end_time(&self) -> f32 {
+        self.start_time() + self.duration()
+    }
+    /// Appends `next` to a sequence, auto-assigning its `start_time` to begin right where this
+    /// step ends; animations given an explicit `with_start_time` keep it. See [`Sequence`] for
+    /// chaining past the first step.
+    pub fn then(self, next: EntityAnimations) -> Vec<EntityAnimations> {
+        vec![self].then(next)
+    }
+    /// As `then`, but starts `next` `overlap` seconds before this step ends, with this step's
+    /// weight fading out and `next`'s weight fading in across that overlap so
+    /// [`Animations::blend`] ramps smoothly between them instead of averaging them flat for the
+    /// whole span.
+    pub fn then_with_crossfade(self, next: EntityAnimations, overlap: f32) -> Vec<EntityAnimations> {
+        vec![self].then_with_crossfade(next, overlap)
+    }
     pub fn set_properties(&mut self, start_time: f32, duration: f32, rate_func: EaseType) {
         for animation in self.animations.iter_mut() {
             match animation {
@@ -301,10 +901,213 @@ impl EntityAnimations {
             }
         }
     }
+    /// Moves this step's `start_time`, leaving its `duration`/`rate_func` untouched; animations
+    /// given an explicit `with_start_time` keep theirs. Used by
+    /// `then`/`then_with_crossfade` to chain steps without coupling a step's own timing to the
+    /// step before it.
+    pub(crate) fn set_start_time(&mut self, start_time: f32) {
+        for animation in self.animations.iter_mut() {
+            match animation {
+                AnimationType::StrokeColor(ref mut animation) => {
+                    set_start_time(animation, start_time)
+                }
+                AnimationType::FillColor(ref mut animation) => {
+                    set_start_time(animation, start_time)
+                }
+                AnimationType::Position(ref mut animation) => {
+                    set_start_time(animation, start_time)
+                }
+                AnimationType::Angle(ref mut animation) => set_start_time(animation, start_time),
+                AnimationType::Size(ref mut animation) => set_start_time(animation, start_time),
+                AnimationType::Opacity(ref mut animation) => {
+                    set_start_time(animation, start_time)
+                }
+                AnimationType::PathCompletion(ref mut animation) => {
+                    set_start_time(animation, start_time)
+                }
+            }
+        }
+    }
+    /// Ramps this step's animations' weight up from `0.0` over `seconds`. Used by
+    /// `then_with_crossfade` to ease in the incoming side of a crossfade.
+    pub(crate) fn set_fade_in(&mut self, seconds: f32) {
+        for animation in self.animations.iter_mut() {
+            match animation {
+                AnimationType::StrokeColor(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::FillColor(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::Position(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::Angle(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::Size(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::Opacity(ref mut animation) => set_fade_in(animation, seconds),
+                AnimationType::PathCompletion(ref mut animation) => set_fade_in(animation, seconds),
+            }
+        }
+    }
+    /// Ramps this step's animations' weight down to `0.0` over the `seconds` before it ends.
+    /// Used by `then_with_crossfade` to ease out the outgoing side of a crossfade.
+    pub(crate) fn set_fade_out(&mut self, seconds: f32) {
+        for animation in self.animations.iter_mut() {
+            match animation {
+                AnimationType::StrokeColor(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::FillColor(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::Position(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::Angle(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::Size(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::Opacity(ref mut animation) => set_fade_out(animation, seconds),
+                AnimationType::PathCompletion(ref mut animation) => set_fade_out(animation, seconds),
+            }
+        }
+    }
 }
 
 impl Into<Vec<EntityAnimations>> for EntityAnimations {
     fn into(self) -> Vec<EntityAnimations> {
         vec![self]
     }
+}
+
+/// Fluent chaining past the first [`EntityAnimations::then`]/`then_with_crossfade` call, so
+/// "move, then rotate, then fade" reads as a single chain without manually computing
+/// `with_start_time` offsets for each step.
+pub trait Sequence {
+    fn then(self, next: EntityAnimations) -> Vec<EntityAnimations>;
+    fn then_with_crossfade(self, next: EntityAnimations, overlap: f32) -> Vec<EntityAnimations>;
+}
+
+impl Sequence for Vec<EntityAnimations> {
+    fn then(self, next: EntityAnimations) -> Vec<EntityAnimations> {
+        self.then_with_crossfade(next, 0.0)
+    }
+
+    fn then_with_crossfade(mut self, mut next: EntityAnimations, overlap: f32) -> Vec<EntityAnimations> {
+        if let Some(prev) = self.last_mut() {
+            let overlap = overlap.max(0.0);
+            let start_time = (prev.end_time() - overlap).max(prev.start_time());
+            next.set_start_time(start_time);
+            if overlap > 0.0 {
+                prev.set_fade_out(overlap);
+                next.set_fade_in(overlap);
+            }
+        }
+        self.push(next);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal stand-in for a real animatable property (`Position`, `Opacity`, etc., which live
+    /// outside this module) so the progress/keyframe/fade math below can be exercised without
+    /// depending on any one property's concrete shape.
+    #[derive(Component, Debug, Clone, Copy, PartialEq)]
+    struct TestValue(f32);
+
+    impl Interpolate for TestValue {
+        fn interp(&self, other: &Self, t: f32) -> Self {
+            TestValue(self.0 + (other.0 - self.0) * t)
+        }
+    }
+
+    impl std::ops::Add for TestValue {
+        type Output = TestValue;
+        fn add(self, rhs: TestValue) -> TestValue {
+            TestValue(self.0 + rhs.0)
+        }
+    }
+
+    impl std::ops::Sub for TestValue {
+        type Output = TestValue;
+        fn sub(self, rhs: TestValue) -> TestValue {
+            TestValue(self.0 - rhs.0)
+        }
+    }
+
+    #[test]
+    fn ping_pong_terminal_frame_settles_at_begin_not_end() {
+        let anim = Animation::to(TestValue(1.0))
+            .with_duration(1.0)
+            .with_repeat(Repeat::Count(2))
+            .with_ping_pong();
+
+        let just_before_end = anim.active_progress(1.9999).unwrap();
+        assert!(
+            just_before_end < 0.001,
+            "expected the approach to begin just before the seam, got {just_before_end}"
+        );
+
+        let terminal = anim.active_progress(2.0).unwrap();
+        assert!(
+            terminal < 1e-6,
+            "terminal frame of an even repeat count should settle at begin (0.0), got {terminal}"
+        );
+    }
+
+    #[test]
+    fn ping_pong_mid_sequence_seam_stays_continuous() {
+        // Count(2), duration 1.0: elapsed == 1.0 is simultaneously the end of cycle 0 (forward)
+        // and the start of cycle 1 (reversed). Both should land on local progress 1.0 so the
+        // property doesn't snap at the seam.
+        let anim = Animation::to(TestValue(1.0))
+            .with_duration(1.0)
+            .with_repeat(Repeat::Count(2))
+            .with_ping_pong();
+
+        let just_before_seam = anim.active_progress(0.9999).unwrap();
+        let at_seam = anim.active_progress(1.0).unwrap();
+        assert!(
+            (at_seam - just_before_seam).abs() < 0.001,
+            "expected the seam frame to be continuous with the cycle approaching it, \
+             got {just_before_seam} -> {at_seam}"
+        );
+    }
+
+    #[test]
+    fn keyframe_segment_lookup_at_exact_offsets() {
+        let keyframes = vec![
+            Keyframe {
+                offset: 0.0,
+                value: TestValue(0.0),
+                ease: EaseType::Linear,
+            },
+            Keyframe {
+                offset: 0.5,
+                value: TestValue(10.0),
+                ease: EaseType::Linear,
+            },
+            Keyframe {
+                offset: 1.0,
+                value: TestValue(20.0),
+                ease: EaseType::Linear,
+            },
+        ];
+
+        assert_eq!(
+            Animation::<TestValue>::sample_keyframes(&keyframes, 0.0),
+            Some(TestValue(0.0))
+        );
+        assert_eq!(
+            Animation::<TestValue>::sample_keyframes(&keyframes, 0.5),
+            Some(TestValue(10.0))
+        );
+        assert_eq!(
+            Animation::<TestValue>::sample_keyframes(&keyframes, 1.0),
+            Some(TestValue(20.0))
+        );
+    }
+
+    #[test]
+    fn fade_factor_ramps_in_and_out_across_the_overlap() {
+        let anim = Animation::to(TestValue(1.0))
+            .with_duration(2.0)
+            .with_fade_in(0.5)
+            .with_fade_out(0.5);
+
+        assert_eq!(anim.fade_factor(0.0), 0.0);
+        assert_eq!(anim.fade_factor(0.25), 0.5);
+        assert_eq!(anim.fade_factor(1.0), 1.0);
+        assert_eq!(anim.fade_factor(1.75), 0.5);
+        assert_eq!(anim.fade_factor(2.0), 0.0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+/// Rate functions used to remap normalized animation progress (`t ∈ [0, 1]`) before
+/// interpolating between `begin` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EaseType {
+    Linear,
+    Quint,
+    /// Underdamped/critically damped/overdamped closed-form spring response, with `t` scaled so
+    /// that `1.0` lands where the envelope `e^(-zeta*w0*T)` drops below `SPRING_SETTLE_EPSILON`.
+    Spring {
+        stiffness: f32,
+        damping: f32,
+        mass: f32,
+    },
+}
+
+/// Envelope `e^(-zeta*w0*T)` threshold below which a spring is considered settled.
+const SPRING_SETTLE_EPSILON: f32 = 0.001;
+
+impl EaseType {
+    /// Evaluates this rate function at `t`.
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            EaseType::Linear => t,
+            EaseType::Quint => {
+                if t < 0.5 {
+                    16.0 * t.powi(5)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(5) / 2.0
+                }
+            }
+            EaseType::Spring {
+                stiffness,
+                damping,
+                mass,
+            } => spring_response(*stiffness, *damping, *mass, t),
+        }
+    }
+}
+
+fn spring_response(stiffness: f32, damping: f32, mass: f32, t: f32) -> f32 {
+    let w0 = (stiffness / mass).sqrt();
+    let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+    let decay_rate = zeta * w0;
+
+    let settle_time = if decay_rate > 0.0 {
+        -SPRING_SETTLE_EPSILON.ln() / decay_rate
+    } else {
+        // Undamped: never settles, so just use one period as the window.
+        2.0 * std::f32::consts::PI / w0
+    };
+    let time = t * settle_time;
+
+    if (zeta - 1.0).abs() < 1e-4 {
+        // Critically damped.
+        1.0 - (-w0 * time).exp() * (1.0 + w0 * time)
+    } else if zeta < 1.0 {
+        // Underdamped: overshoots and oscillates while settling.
+        let wd = w0 * (1.0 - zeta * zeta).sqrt();
+        1.0 - (-zeta * w0 * time).exp() * ((wd * time).cos() + (zeta * w0 / wd) * (wd * time).sin())
+    } else {
+        // Overdamped: approaches without overshoot.
+        let wd = w0 * (zeta * zeta - 1.0).sqrt();
+        1.0 - (-zeta * w0 * time).exp()
+            * ((wd * time).cosh() + (zeta * w0 / wd) * (wd * time).sinh())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_and_quint_are_identity_at_the_ends() {
+        assert_eq!(EaseType::Linear.apply(0.0), 0.0);
+        assert_eq!(EaseType::Linear.apply(1.0), 1.0);
+        assert_eq!(EaseType::Quint.apply(0.0), 0.0);
+        assert_eq!(EaseType::Quint.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn spring_starts_at_rest_and_settles_at_one() {
+        let spring = EaseType::Spring {
+            stiffness: 170.0,
+            damping: 10.0,
+            mass: 1.0,
+        };
+        assert_eq!(spring.apply(0.0), 0.0);
+        let settled = spring.apply(1.0);
+        assert!(
+            (settled - 1.0).abs() < 0.01,
+            "expected settle_time to land within the settle envelope of 1.0, got {settled}"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod ease;
+pub mod animation;
+
+pub use ease::EaseType;